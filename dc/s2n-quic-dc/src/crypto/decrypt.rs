@@ -0,0 +1,43 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Errors produced while bringing up or using a receiver's decryption key.
+#[derive(Clone, Copy, Debug)]
+pub enum Error {
+    /// The key has definitely already been used to decrypt a packet with this same nonce.
+    ReplayDefinitelyDetected,
+    /// The key may have already been used to decrypt a packet with this same nonce; `gap` is
+    /// the distance, if known, between the packet number and the edge of the replay window.
+    ReplayPotentiallyDetected { gap: Option<u64> },
+    /// The AEAD tag did not authenticate.
+    InvalidTag,
+    /// The TLS handshake failed before a decryption key could be derived, so the peer's alert
+    /// is surfaced here instead of a key-specific failure.
+    HandshakeFailed { alert: u8 },
+}
+
+/// Tracks whether a receiver's decryption key is usable yet.
+///
+/// A receiver can't decrypt anything until its crypto handshake completes; if the handshake
+/// fails instead, there is no key to report the failure through, so the TLS provider reports it
+/// here and the receive pipeline surfaces it the same way it would a decrypt failure.
+#[derive(Clone, Copy, Debug)]
+pub enum HandshakeStatus {
+    /// The handshake has not completed yet.
+    Pending,
+    /// The handshake completed and a decryption key is available.
+    Complete,
+    /// The handshake failed with the given TLS alert before a key could be derived.
+    Failed { alert: u8 },
+}
+
+impl HandshakeStatus {
+    /// Returns the error the receive pipeline should surface, if the handshake has failed.
+    #[inline]
+    pub fn check(self) -> Result<(), Error> {
+        match self {
+            Self::Pending | Self::Complete => Ok(()),
+            Self::Failed { alert } => Err(Error::HandshakeFailed { alert }),
+        }
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{crypto::decrypt, packet::stream};
-use s2n_quic_core::{buffer, frame};
+use s2n_quic_core::{buffer, frame, varint::VarInt};
 
 #[derive(Clone, Copy, Debug, thiserror::Error)]
 pub enum Error {
@@ -39,6 +39,8 @@ pub enum Error {
     ApplicationError {
         error: s2n_quic_core::application::Error,
     },
+    #[error("the TLS handshake failed with alert {alert}")]
+    CryptoError { alert: u8 },
 }
 
 impl From<decrypt::Error> for Error {
@@ -49,6 +51,7 @@ impl From<decrypt::Error> for Error {
                 Self::KeyReplayMaybePrevented { gap }
             }
             decrypt::Error::InvalidTag => Self::Decrypt,
+            decrypt::Error::HandshakeFailed { alert } => Self::CryptoError { alert },
         }
     }
 }
@@ -68,30 +71,62 @@ impl Error {
         )
     }
 
+    /// Builds the CONNECTION_CLOSE frame this error should be reported to the peer as, if any.
+    ///
+    /// `reason` is scratch space the caller owns: when this error has a dynamic reason phrase
+    /// (e.g. `StreamMismatch`), it is formatted into `reason` and the returned frame borrows it,
+    /// rather than allocating (and leaking) a fresh owned buffer on every close.
     #[inline]
-    pub(super) fn connection_close(&self) -> Option<frame::ConnectionClose<'static>> {
+    pub(super) fn connection_close<'a>(
+        &self,
+        reason: &'a mut String,
+    ) -> Option<frame::ConnectionClose<'a>> {
         use s2n_quic_core::transport;
-        match self {
+        use std::fmt::Write;
+
+        // the frame type that's carrying a STREAM frame payload, for errors that are specific
+        // to a single stream and can point the peer at the offending frame
+        const STREAM_FRAME_TYPE: VarInt = VarInt::from_u8(0x08);
+
+        let close: transport::Error = match self {
             Error::Decode
             | Error::Decrypt
             | Error::Duplicate
-            | Error::StreamMismatch { .. }
             | Error::UnexpectedRetransmission => {
                 // return protocol violation for the errors that are only fatal for reliable
                 // transports
-                Some(transport::Error::PROTOCOL_VIOLATION.into())
+                transport::Error::PROTOCOL_VIOLATION
+            }
+            Error::StreamMismatch { expected, actual } => {
+                let _ = write!(reason, "expected stream {expected} got {actual}");
+                transport::Error::PROTOCOL_VIOLATION.with_frame_type(STREAM_FRAME_TYPE)
             }
-            Error::IdleTimeout => None,
-            Error::MaxDataExceeded => Some(transport::Error::FLOW_CONTROL_ERROR.into()),
-            Error::InvalidFin | Error::TruncatedTransport => {
-                Some(transport::Error::FINAL_SIZE_ERROR.into())
+            Error::IdleTimeout => return None,
+            Error::MaxDataExceeded => transport::Error::FLOW_CONTROL_ERROR,
+            Error::InvalidFin | Error::TruncatedTransport => transport::Error::FINAL_SIZE_ERROR,
+            Error::OutOfOrder { expected, actual } => {
+                let _ = write!(reason, "expected offset {expected} got {actual}");
+                transport::Error::STREAM_STATE_ERROR.with_frame_type(STREAM_FRAME_TYPE)
             }
-            Error::OutOfOrder { .. } => Some(transport::Error::STREAM_STATE_ERROR.into()),
-            Error::OutOfRange => Some(transport::Error::STREAM_LIMIT_ERROR.into()),
+            Error::OutOfRange => transport::Error::STREAM_LIMIT_ERROR,
             // we don't have working crypto keys so we can't respond
-            Error::KeyReplayPrevented | Error::KeyReplayMaybePrevented { .. } => None,
-            Error::ApplicationError { error } => Some((*error).into()),
+            Error::KeyReplayPrevented | Error::KeyReplayMaybePrevented { .. } => return None,
+            Error::ApplicationError { error } => return Some((*error).into()),
+            Error::CryptoError { alert } => {
+                let _ = write!(reason, "the TLS handshake failed with alert {alert}");
+                // map the TLS alert into the QUIC crypto error code space: alert `N` becomes
+                // `0x0100 + N`, which peers decode back into the alert description
+                transport::Error::new(transport::Code::new(0x0100 | *alert as u64))
+            }
+        };
+
+        let mut close: frame::ConnectionClose<'a> = close.into();
+
+        if !reason.is_empty() {
+            close.reason = Some(reason.as_bytes());
         }
+
+        Some(close)
     }
 }
 
@@ -132,6 +167,22 @@ impl From<Error> for std::io::ErrorKind {
             Error::KeyReplayPrevented => ErrorKind::PermissionDenied,
             Error::KeyReplayMaybePrevented { .. } => ErrorKind::PermissionDenied,
             Error::ApplicationError { .. } => ErrorKind::ConnectionReset,
+            Error::CryptoError { alert } => alert_error_kind(alert),
         }
     }
 }
+
+/// Classifies a TLS 1.3 alert description (RFC 8446, section 6) as either a rejection of our
+/// identity (`PermissionDenied`) or a malformed/incompatible handshake (`InvalidData`).
+#[inline]
+fn alert_error_kind(alert: u8) -> std::io::ErrorKind {
+    use std::io::ErrorKind;
+
+    match alert {
+        // bad_certificate, unsupported_certificate, certificate_revoked, certificate_expired,
+        // certificate_unknown, access_denied, unknown_psk_identity, certificate_required: the
+        // peer rejected our identity rather than finding the handshake malformed
+        42 | 43 | 44 | 45 | 46 | 49 | 115 | 116 => ErrorKind::PermissionDenied,
+        _ => ErrorKind::InvalidData,
+    }
+}
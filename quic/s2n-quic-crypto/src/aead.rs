@@ -0,0 +1,86 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! AEAD key construction dispatched over the negotiated [`CipherSuite`].
+//!
+//! Each QUIC key epoch needs to seal/open with whichever suite was negotiated for the
+//! connection, rather than a single hard-coded algorithm. [`Key`] does that dispatch once, at
+//! construction, so the epoch's hot path never has to match on the suite per packet.
+
+use crate::{aws_lc_aead as aead, CipherSuite};
+use aws_lc_rs::error::Unspecified;
+
+/// An AEAD key for one of the negotiable cipher suites.
+///
+/// Wraps `aws_lc_rs`'s [`aead::LessSafeKey`], which lets the caller supply its own per-packet
+/// nonce (derived from the packet number, as QUIC requires) instead of the self-incrementing
+/// nonce `aead::SealingKey`/`aead::OpeningKey` assume.
+pub struct Key {
+    cipher_suite: CipherSuite,
+    key: aead::LessSafeKey,
+}
+
+impl Key {
+    /// Builds a key for `cipher_suite` from `key_material`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key_material.len()` does not match `cipher_suite.key_len()`.
+    #[inline]
+    pub fn new(cipher_suite: CipherSuite, key_material: &[u8]) -> Self {
+        assert_eq!(
+            key_material.len(),
+            cipher_suite.key_len(),
+            "key material length does not match the cipher suite's key length"
+        );
+
+        let unbound = aead::UnboundKey::new(cipher_suite.aead_algorithm(), key_material)
+            .expect("key material was already validated against the cipher suite's key length");
+
+        Self {
+            cipher_suite,
+            key: aead::LessSafeKey::new(unbound),
+        }
+    }
+
+    /// The cipher suite this key was constructed for.
+    #[inline]
+    pub fn cipher_suite(&self) -> CipherSuite {
+        self.cipher_suite
+    }
+
+    /// The length, in bytes, of the authentication tag this key's AEAD algorithm appends.
+    #[inline]
+    pub fn tag_len(&self) -> usize {
+        self.cipher_suite.aead_algorithm().tag_len()
+    }
+
+    /// The length, in bytes, of the nonce this key's AEAD algorithm expects.
+    #[inline]
+    pub fn nonce_len(&self) -> usize {
+        self.cipher_suite.nonce_len()
+    }
+
+    /// Seals `in_out` in place with `nonce` and `aad`, appending the authentication tag.
+    #[inline]
+    pub fn seal_in_place(
+        &self,
+        nonce: aead::Nonce,
+        aad: aead::Aad<&[u8]>,
+        in_out: &mut Vec<u8>,
+    ) -> Result<(), Unspecified> {
+        self.key.seal_in_place_append_tag(nonce, aad, in_out)
+    }
+
+    /// Opens `in_out` in place with `nonce` and `aad`, returning the plaintext slice with the
+    /// authentication tag removed.
+    #[inline]
+    pub fn open_in_place<'a>(
+        &self,
+        nonce: aead::Nonce,
+        aad: aead::Aad<&[u8]>,
+        in_out: &'a mut [u8],
+    ) -> Result<&'a mut [u8], Unspecified> {
+        self.key.open_in_place(nonce, aad, in_out)
+    }
+}
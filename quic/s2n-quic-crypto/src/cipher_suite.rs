@@ -0,0 +1,97 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Negotiable TLS 1.3 cipher suites.
+//!
+//! The handshake, 0-RTT and 1-RTT key modules pick their AEAD algorithm from the negotiated
+//! [`CipherSuite`] rather than hard-coding AES-128-GCM, so an endpoint can prefer
+//! ChaCha20-Poly1305 on platforms without AES hardware acceleration. Initial and Retry protection
+//! always use AES-128-GCM.
+
+use crate::aws_lc_aead as aead;
+
+/// A TLS 1.3 cipher suite this implementation is able to negotiate.
+///
+/// See <https://www.rfc-editor.org/rfc/rfc8446#appendix-B.4> for the suites defined by TLS 1.3.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum CipherSuite {
+    Aes128GcmSha256,
+    Aes256GcmSha384,
+    Chacha20Poly1305Sha256,
+}
+
+impl CipherSuite {
+    /// All of the cipher suites this implementation supports, in the default preference order
+    /// (hardware-accelerated AES first).
+    pub const ALL: [Self; 3] = [
+        Self::Aes128GcmSha256,
+        Self::Aes256GcmSha384,
+        Self::Chacha20Poly1305Sha256,
+    ];
+
+    /// Returns the `aws-lc-rs` AEAD algorithm backing this cipher suite.
+    #[inline]
+    pub fn aead_algorithm(self) -> &'static aead::Algorithm {
+        match self {
+            Self::Aes128GcmSha256 => &aead::AES_128_GCM,
+            Self::Aes256GcmSha384 => &aead::AES_256_GCM,
+            Self::Chacha20Poly1305Sha256 => &aead::CHACHA20_POLY1305,
+        }
+    }
+
+    /// The length, in bytes, of the AEAD key for this cipher suite.
+    #[inline]
+    pub fn key_len(self) -> usize {
+        self.aead_algorithm().key_len()
+    }
+
+    /// The length, in bytes, of the AEAD nonce for this cipher suite.
+    #[inline]
+    pub fn nonce_len(self) -> usize {
+        self.aead_algorithm().nonce_len()
+    }
+}
+
+impl Default for CipherSuite {
+    #[inline]
+    fn default() -> Self {
+        Self::Aes128GcmSha256
+    }
+}
+
+/// The set of cipher suites an endpoint is willing to negotiate, in preference order.
+///
+/// Use [`CipherSuitePreference::default`] for the repo's default ordering, or
+/// [`CipherSuitePreference::restrict`] to narrow or reorder it, e.g. to prefer
+/// ChaCha20-Poly1305 on a mobile target lacking AES hardware acceleration.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CipherSuitePreference(Vec<CipherSuite>);
+
+impl CipherSuitePreference {
+    /// Restricts negotiation to exactly the given suites, in the given preference order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `suites` is empty, since an endpoint must be willing to negotiate at least one
+    /// cipher suite.
+    pub fn restrict(suites: &[CipherSuite]) -> Self {
+        assert!(
+            !suites.is_empty(),
+            "at least one cipher suite must be enabled"
+        );
+        Self(suites.to_vec())
+    }
+
+    /// Returns the configured suites, in preference order.
+    #[inline]
+    pub fn as_slice(&self) -> &[CipherSuite] {
+        &self.0
+    }
+}
+
+impl Default for CipherSuitePreference {
+    #[inline]
+    fn default() -> Self {
+        Self(CipherSuite::ALL.to_vec())
+    }
+}
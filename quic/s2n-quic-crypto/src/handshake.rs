@@ -0,0 +1,53 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Handshake packet protection.
+//!
+//! Unlike [`crate::initial`] and [`crate::retry`], the handshake epoch is keyed with whichever
+//! [`CipherSuite`] the TLS handshake actually negotiates (see [`crate::Suite::negotiate`]), so
+//! its key construction takes that suite as a parameter instead of hard-coding one.
+
+use crate::{aead, header_key, iv::Iv, CipherSuite};
+
+pub struct HandshakeKey {
+    key: aead::Key,
+    iv: Iv,
+}
+
+impl HandshakeKey {
+    #[inline]
+    pub fn new(cipher_suite: CipherSuite, key_material: &[u8], iv_material: [u8; Iv::LEN]) -> Self {
+        Self {
+            key: aead::Key::new(cipher_suite, key_material),
+            iv: Iv::new(iv_material),
+        }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &aead::Key {
+        &self.key
+    }
+
+    #[inline]
+    pub fn iv(&self) -> &Iv {
+        &self.iv
+    }
+}
+
+pub struct HandshakeHeaderKey {
+    key: header_key::Key,
+}
+
+impl HandshakeHeaderKey {
+    #[inline]
+    pub fn new(cipher_suite: CipherSuite, key_material: &[u8]) -> Self {
+        Self {
+            key: header_key::Key::new(cipher_suite, key_material),
+        }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &header_key::Key {
+        &self.key
+    }
+}
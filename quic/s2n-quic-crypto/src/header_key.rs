@@ -0,0 +1,65 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! QUIC header protection key construction dispatched over the negotiated [`CipherSuite`].
+//!
+//! RFC 9001 section 5.4 derives the header protection mask from a different primitive per AEAD:
+//! AES-ECB for the two AES suites, the ChaCha20 block function for ChaCha20-Poly1305. [`Key`]
+//! picks the right one at construction so epoch modules don't have to match on the suite again
+//! every time they unprotect a header.
+
+use crate::{aws_lc_aead::quic as hp, CipherSuite};
+
+impl CipherSuite {
+    /// Returns the `aws-lc-rs` header protection algorithm backing this cipher suite.
+    #[inline]
+    fn header_protection_algorithm(self) -> &'static hp::Algorithm {
+        match self {
+            Self::Aes128GcmSha256 => &hp::AES_128,
+            Self::Aes256GcmSha384 => &hp::AES_256,
+            Self::Chacha20Poly1305Sha256 => &hp::CHACHA20,
+        }
+    }
+}
+
+/// A header protection key for one of the negotiable cipher suites.
+pub struct Key {
+    cipher_suite: CipherSuite,
+    key: hp::HeaderProtectionKey,
+}
+
+impl Key {
+    /// Builds a header protection key for `cipher_suite` from `key_material`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key_material.len()` does not match `cipher_suite.key_len()`.
+    #[inline]
+    pub fn new(cipher_suite: CipherSuite, key_material: &[u8]) -> Self {
+        assert_eq!(
+            key_material.len(),
+            cipher_suite.key_len(),
+            "key material length does not match the cipher suite's key length"
+        );
+
+        let key = hp::HeaderProtectionKey::new(cipher_suite.header_protection_algorithm(), key_material)
+            .expect("key material was already validated against the cipher suite's key length");
+
+        Self { cipher_suite, key }
+    }
+
+    /// The cipher suite this key was constructed for.
+    #[inline]
+    pub fn cipher_suite(&self) -> CipherSuite {
+        self.cipher_suite
+    }
+
+    /// Computes the 5-byte header protection mask for `sample`, the fixed-length ciphertext
+    /// sample RFC 9001 section 5.4.2 takes from just past the packet number field.
+    #[inline]
+    pub fn new_mask(&self, sample: &[u8]) -> [u8; 5] {
+        self.key
+            .new_mask(sample)
+            .expect("the sample length is fixed by the header protection algorithm")
+    }
+}
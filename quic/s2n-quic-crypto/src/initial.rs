@@ -0,0 +1,55 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Initial packet protection.
+//!
+//! Initial keys are derived from a fixed, version-specific salt (RFC 9001 section 5.2) rather
+//! than from the negotiated handshake, so they are always AES-128-GCM regardless of the
+//! connection's [`CipherSuite`] preference -- there is nothing to negotiate here.
+
+use crate::{aead, header_key, iv::Iv, CipherSuite};
+
+const INITIAL_CIPHER_SUITE: CipherSuite = CipherSuite::Aes128GcmSha256;
+
+pub struct InitialKey {
+    key: aead::Key,
+    iv: Iv,
+}
+
+impl InitialKey {
+    #[inline]
+    pub fn new(key_material: &[u8], iv_material: [u8; Iv::LEN]) -> Self {
+        Self {
+            key: aead::Key::new(INITIAL_CIPHER_SUITE, key_material),
+            iv: Iv::new(iv_material),
+        }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &aead::Key {
+        &self.key
+    }
+
+    #[inline]
+    pub fn iv(&self) -> &Iv {
+        &self.iv
+    }
+}
+
+pub struct InitialHeaderKey {
+    key: header_key::Key,
+}
+
+impl InitialHeaderKey {
+    #[inline]
+    pub fn new(key_material: &[u8]) -> Self {
+        Self {
+            key: header_key::Key::new(INITIAL_CIPHER_SUITE, key_material),
+        }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &header_key::Key {
+        &self.key
+    }
+}
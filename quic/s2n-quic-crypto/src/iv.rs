@@ -0,0 +1,37 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The per-packet AEAD nonce, derived from a fixed IV and the packet number.
+
+use crate::aws_lc_aead as aead;
+
+/// A QUIC packet protection IV (RFC 9001 section 5.3).
+///
+/// The nonce for each packet is formed by left-padding the packet number with zeroes to the IV's
+/// length and XOR-ing it with this fixed IV, so a single `Iv` can derive every packet's nonce for
+/// its key's lifetime without storing per-packet state.
+#[derive(Clone, Copy)]
+pub struct Iv([u8; Self::LEN]);
+
+impl Iv {
+    /// All of this crate's cipher suites use a 12-byte IV.
+    pub const LEN: usize = 12;
+
+    #[inline]
+    pub fn new(bytes: [u8; Self::LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Derives the AEAD nonce for `packet_number`.
+    #[inline]
+    pub fn nonce(&self, packet_number: u64) -> aead::Nonce {
+        let mut nonce = self.0;
+        let packet_number = packet_number.to_be_bytes();
+
+        for (n, pn) in nonce.iter_mut().rev().zip(packet_number.iter().rev()) {
+            *n ^= pn;
+        }
+
+        aead::Nonce::assume_unique_for_key(nonce)
+    }
+}
@@ -6,13 +6,14 @@ std::compile_error!("feature `fips` is not supported on windows");
 
 #[macro_use]
 mod negotiated;
-#[macro_use]
-mod header_key;
 
 mod aead;
-mod cipher_suite;
+pub mod cipher_suite;
+mod header_key;
 mod iv;
 
+pub use cipher_suite::{CipherSuite, CipherSuitePreference};
+
 #[doc(hidden)]
 pub use aws_lc_rs::{
     aead as aws_lc_aead, aead::MAX_TAG_LEN, constant_time, digest, hkdf, hkdf::Prk, hmac,
@@ -30,8 +31,108 @@ pub mod one_rtt;
 pub mod retry;
 pub mod zero_rtt;
 
-#[derive(Clone, Copy, Debug, Default)]
-pub struct Suite;
+/// The cipher suites a [`Suite`] negotiates with, and the AEAD construction used for each QUIC
+/// key epoch.
+///
+/// Defaults to [`CipherSuitePreference::default`], which offers every suite in `ALL`. Restrict
+/// it with [`Suite::with_cipher_suite_preference`] to run only TLS_CHACHA20_POLY1305_SHA256,
+/// TLS_AES_256_GCM_SHA384, or some other subset, e.g. to prefer ChaCha20-Poly1305 on targets
+/// without AES hardware acceleration.
+///
+/// [`Suite::negotiate`] is the entry point a TLS provider calls once per handshake to pick the
+/// suite from this preference. [`Suite::handshake_keys`], [`Suite::one_rtt_keys`], and
+/// [`Suite::zero_rtt_keys`] do that negotiation and thread the result into the [`handshake`],
+/// [`one_rtt`], and [`zero_rtt`] key constructors in one step, so a caller with the peer's
+/// offered suites and the derived key material never has to match on [`CipherSuite`] itself.
+/// [`initial`] and [`retry`] are exempt -- RFC 9001 fixes both to AES-128-GCM regardless of what
+/// the handshake negotiates.
+#[derive(Clone, Debug, Default)]
+pub struct Suite {
+    cipher_suite_preference: CipherSuitePreference,
+}
+
+impl Suite {
+    /// Restricts or reorders the cipher suites this `Suite` is willing to negotiate.
+    #[inline]
+    pub fn with_cipher_suite_preference(mut self, preference: CipherSuitePreference) -> Self {
+        self.cipher_suite_preference = preference;
+        self
+    }
+
+    /// Returns the configured cipher suite preference.
+    #[inline]
+    pub fn cipher_suite_preference(&self) -> &CipherSuitePreference {
+        &self.cipher_suite_preference
+    }
+
+    /// Picks the cipher suite to use for the connection out of the suites the peer `offered`.
+    ///
+    /// Returns the first suite in *our* preference order that the peer also offered, mirroring
+    /// TLS 1.3's server-preference negotiation model. Returns `None` if the two endpoints have
+    /// no suite in common, in which case the handshake must fail.
+    #[inline]
+    pub fn negotiate(&self, offered: &[CipherSuite]) -> Option<CipherSuite> {
+        self.cipher_suite_preference
+            .as_slice()
+            .iter()
+            .copied()
+            .find(|suite| offered.contains(suite))
+    }
+
+    /// Negotiates a cipher suite from `offered` and builds the handshake epoch's keys for it.
+    ///
+    /// Returns `None` if [`Suite::negotiate`] finds no suite in common with the peer.
+    #[inline]
+    pub fn handshake_keys(
+        &self,
+        offered: &[CipherSuite],
+        key_material: &[u8],
+        header_key_material: &[u8],
+        iv_material: [u8; iv::Iv::LEN],
+    ) -> Option<(handshake::HandshakeKey, handshake::HandshakeHeaderKey)> {
+        let cipher_suite = self.negotiate(offered)?;
+        Some((
+            handshake::HandshakeKey::new(cipher_suite, key_material, iv_material),
+            handshake::HandshakeHeaderKey::new(cipher_suite, header_key_material),
+        ))
+    }
+
+    /// Negotiates a cipher suite from `offered` and builds the 1-RTT epoch's keys for it.
+    ///
+    /// Returns `None` if [`Suite::negotiate`] finds no suite in common with the peer.
+    #[inline]
+    pub fn one_rtt_keys(
+        &self,
+        offered: &[CipherSuite],
+        key_material: &[u8],
+        header_key_material: &[u8],
+        iv_material: [u8; iv::Iv::LEN],
+    ) -> Option<(one_rtt::OneRttKey, one_rtt::OneRttHeaderKey)> {
+        let cipher_suite = self.negotiate(offered)?;
+        Some((
+            one_rtt::OneRttKey::new(cipher_suite, key_material, iv_material),
+            one_rtt::OneRttHeaderKey::new(cipher_suite, header_key_material),
+        ))
+    }
+
+    /// Negotiates a cipher suite from `offered` and builds the 0-RTT epoch's keys for it.
+    ///
+    /// Returns `None` if [`Suite::negotiate`] finds no suite in common with the peer.
+    #[inline]
+    pub fn zero_rtt_keys(
+        &self,
+        offered: &[CipherSuite],
+        key_material: &[u8],
+        header_key_material: &[u8],
+        iv_material: [u8; iv::Iv::LEN],
+    ) -> Option<(zero_rtt::ZeroRttKey, zero_rtt::ZeroRttHeaderKey)> {
+        let cipher_suite = self.negotiate(offered)?;
+        Some((
+            zero_rtt::ZeroRttKey::new(cipher_suite, key_material, iv_material),
+            zero_rtt::ZeroRttHeaderKey::new(cipher_suite, header_key_material),
+        ))
+    }
+}
 
 impl s2n_quic_core::crypto::CryptoSuite for Suite {
     type HandshakeKey = handshake::HandshakeKey;
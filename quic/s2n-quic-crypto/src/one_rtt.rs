@@ -0,0 +1,53 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! 1-RTT (application data) packet protection.
+//!
+//! Keyed with whichever [`CipherSuite`] the TLS handshake negotiated for the connection (see
+//! [`crate::Suite::negotiate`]); key updates (RFC 9001 section 6) derive a fresh secret but keep
+//! the same cipher suite, so they go through [`OneRttKey::new`] again with the updated material.
+
+use crate::{aead, header_key, iv::Iv, CipherSuite};
+
+pub struct OneRttKey {
+    key: aead::Key,
+    iv: Iv,
+}
+
+impl OneRttKey {
+    #[inline]
+    pub fn new(cipher_suite: CipherSuite, key_material: &[u8], iv_material: [u8; Iv::LEN]) -> Self {
+        Self {
+            key: aead::Key::new(cipher_suite, key_material),
+            iv: Iv::new(iv_material),
+        }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &aead::Key {
+        &self.key
+    }
+
+    #[inline]
+    pub fn iv(&self) -> &Iv {
+        &self.iv
+    }
+}
+
+pub struct OneRttHeaderKey {
+    key: header_key::Key,
+}
+
+impl OneRttHeaderKey {
+    #[inline]
+    pub fn new(cipher_suite: CipherSuite, key_material: &[u8]) -> Self {
+        Self {
+            key: header_key::Key::new(cipher_suite, key_material),
+        }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &header_key::Key {
+        &self.key
+    }
+}
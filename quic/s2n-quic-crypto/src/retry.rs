@@ -0,0 +1,37 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Retry packet integrity protection.
+//!
+//! The Retry integrity tag (RFC 9001 section 5.8) is always computed with the fixed,
+//! version-specific AES-128-GCM key and IV published alongside the QUIC version -- it does not
+//! participate in cipher suite negotiation at all.
+
+use crate::{aead, iv::Iv, CipherSuite};
+
+const RETRY_CIPHER_SUITE: CipherSuite = CipherSuite::Aes128GcmSha256;
+
+pub struct RetryKey {
+    key: aead::Key,
+    iv: Iv,
+}
+
+impl RetryKey {
+    #[inline]
+    pub fn new(key_material: &[u8], iv_material: [u8; Iv::LEN]) -> Self {
+        Self {
+            key: aead::Key::new(RETRY_CIPHER_SUITE, key_material),
+            iv: Iv::new(iv_material),
+        }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &aead::Key {
+        &self.key
+    }
+
+    #[inline]
+    pub fn iv(&self) -> &Iv {
+        &self.iv
+    }
+}
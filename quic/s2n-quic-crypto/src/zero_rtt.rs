@@ -0,0 +1,63 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! 0-RTT cross-connection anti-replay protection.
+//!
+//! 0-RTT early data is inherently replayable: nothing about the AEAD prevents an attacker from
+//! resubmitting a captured 0-RTT packet on a brand new connection. [`AntiReplay`] closes that
+//! gap with a time-bounded strike register a server plugs in to reject any early-data token it
+//! has already seen.
+
+pub mod anti_replay;
+
+pub use anti_replay::AntiReplay;
+
+use crate::{aead, header_key, iv::Iv, CipherSuite};
+
+/// 0-RTT early data packet protection.
+///
+/// Keyed with whichever [`CipherSuite`] the server selects for resumption (the same suite the
+/// original connection negotiated, per RFC 8446 section 4.2.11), independent of [`AntiReplay`],
+/// which is the separate cross-connection check every 0-RTT packet must also pass.
+pub struct ZeroRttKey {
+    key: aead::Key,
+    iv: Iv,
+}
+
+impl ZeroRttKey {
+    #[inline]
+    pub fn new(cipher_suite: CipherSuite, key_material: &[u8], iv_material: [u8; Iv::LEN]) -> Self {
+        Self {
+            key: aead::Key::new(cipher_suite, key_material),
+            iv: Iv::new(iv_material),
+        }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &aead::Key {
+        &self.key
+    }
+
+    #[inline]
+    pub fn iv(&self) -> &Iv {
+        &self.iv
+    }
+}
+
+pub struct ZeroRttHeaderKey {
+    key: header_key::Key,
+}
+
+impl ZeroRttHeaderKey {
+    #[inline]
+    pub fn new(cipher_suite: CipherSuite, key_material: &[u8]) -> Self {
+        Self {
+            key: header_key::Key::new(cipher_suite, key_material),
+        }
+    }
+
+    #[inline]
+    pub fn key(&self) -> &header_key::Key {
+        &self.key
+    }
+}
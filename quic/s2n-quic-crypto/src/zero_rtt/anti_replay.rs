@@ -0,0 +1,278 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, hash::Hash, time::Duration};
+
+/// The default window over which an early-data token is remembered.
+pub const DEFAULT_ACCEPTANCE_WINDOW: Duration = Duration::from_secs(10);
+
+/// The default tolerance for a token's `timestamp` reading ahead of the register's `now`.
+///
+/// This is a small allowance for clock skew between whatever clock stamped the token and the
+/// register's own `now`, not a second acceptance window: see [`AntiReplay::check`] for why it
+/// must stay much smaller than the acceptance window.
+pub const DEFAULT_MAX_FUTURE_SKEW: Duration = Duration::from_secs(2);
+
+/// The outcome of checking an early-data token against an [`AntiReplay`] register.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    /// The token had not been seen before and its timestamp falls inside the acceptance window;
+    /// it has been recorded and the 0-RTT packet carrying it may be accepted.
+    Fresh,
+    /// The token has already been seen, its timestamp falls outside the acceptance window, or its
+    /// timestamp is more than the register's future-skew tolerance ahead of `now`.
+    /// The caller should map this to `Error::KeyReplayPrevented` and reject the packet.
+    Replayed,
+}
+
+/// A time-bounded strike register that rejects 0-RTT early-data tokens it has already seen,
+/// across connections.
+///
+/// Per-key nonce-reuse detection (see `decrypt::Error::ReplayDefinitelyDetected`) only protects
+/// a single connection's keys; it does nothing to stop an attacker from capturing a 0-RTT packet
+/// and replaying it against a fresh connection. `AntiReplay` closes that gap by remembering every
+/// token it has accepted for a configurable acceptance window (10 seconds by default), rejecting
+/// duplicates, and rejecting tokens whose own timestamp has already fallen outside that window.
+///
+/// The register is implemented as a pair of rotating buckets, each covering a full acceptance
+/// window. New tokens always go into the "current" bucket; a lookup checks both buckets, since a
+/// token inserted near the end of the current window must still be remembered once the next
+/// window becomes current. A token is therefore remembered for at least the full window (and at
+/// most two windows) after it is first seen, memory use is bounded to O(window) regardless of
+/// traffic volume, and insertion/lookup are both O(1).
+///
+/// `timestamp` and `now` must both be readings of the same monotonic clock -- in practice a
+/// server's wall clock (e.g. seconds since the Unix epoch), since a client's early-data ticket
+/// timestamp has no way to express itself relative to "since this register was created". Bucket
+/// rotation advances off of `now` alone, which is why [`AntiReplay::check`] bounds how far
+/// `timestamp` may run ahead of it: see that method's documentation for the bypass this prevents.
+#[derive(Debug)]
+pub struct AntiReplay<T = Vec<u8>> {
+    window: Duration,
+    max_future_skew: Duration,
+    buckets: [HashSet<T>; 2],
+    /// Index of the bucket currently being written to.
+    current: usize,
+    /// The instant (relative to the register's own clock) at which the current bucket started.
+    current_started_at: Duration,
+}
+
+impl<T> AntiReplay<T>
+where
+    T: Eq + Hash,
+{
+    /// Creates a new register with the [`DEFAULT_ACCEPTANCE_WINDOW`] and
+    /// [`DEFAULT_MAX_FUTURE_SKEW`].
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_window(DEFAULT_ACCEPTANCE_WINDOW)
+    }
+
+    /// Creates a new register with a custom acceptance window and the
+    /// [`DEFAULT_MAX_FUTURE_SKEW`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[inline]
+    pub fn with_window(window: Duration) -> Self {
+        Self::with_window_and_max_future_skew(window, DEFAULT_MAX_FUTURE_SKEW)
+    }
+
+    /// Creates a new register with a custom acceptance window and future-skew tolerance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    #[inline]
+    pub fn with_window_and_max_future_skew(window: Duration, max_future_skew: Duration) -> Self {
+        assert!(!window.is_zero(), "acceptance window must be non-zero");
+        Self {
+            window,
+            max_future_skew,
+            buckets: [HashSet::new(), HashSet::new()],
+            current: 0,
+            current_started_at: Duration::ZERO,
+        }
+    }
+
+    /// Checks `token`, whose early-data ticket claims `timestamp`, against the register at the
+    /// current wall-clock time `now`, and records it if it is fresh.
+    ///
+    /// A token is rejected as [`Outcome::Replayed`], without being recorded, if either:
+    ///
+    /// - `timestamp` is more than the acceptance window behind `now`, i.e. it is stale, or
+    /// - `timestamp` is more than `max_future_skew` ahead of `now`.
+    ///
+    /// The second check is deliberately much tighter than the acceptance window. Bucket
+    /// retention is anchored to `now` (the only clock reading this register's rotation can see),
+    /// so a token allowed to claim a `timestamp` a full window ahead of `now` could be inserted
+    /// while its claimed acceptance band extends up to two windows past where this register has
+    /// actually retained it -- letting a later replay slip through the staleness check after the
+    /// register has already forgotten the token. Bounding `timestamp` to a small skew past `now`
+    /// narrows that gap: retention covers at least a full window past `now`, so the claimed
+    /// acceptance band can outlast it by at most `max_future_skew`.
+    #[inline]
+    pub fn check(&mut self, token: T, timestamp: Duration, now: Duration) -> Outcome {
+        if now.checked_sub(timestamp).is_some_and(|age| age > self.window) {
+            return Outcome::Replayed;
+        }
+
+        if timestamp
+            .checked_sub(now)
+            .is_some_and(|skew| skew > self.max_future_skew)
+        {
+            return Outcome::Replayed;
+        }
+
+        self.advance(now);
+
+        if self.buckets[self.current].contains(&token) || self.buckets[1 - self.current].contains(&token)
+        {
+            return Outcome::Replayed;
+        }
+
+        self.buckets[self.current].insert(token);
+        Outcome::Fresh
+    }
+
+    /// Rotates the buckets so `now` falls within the current window.
+    #[inline]
+    fn advance(&mut self, now: Duration) {
+        if now < self.current_started_at + self.window {
+            return;
+        }
+
+        if now >= self.current_started_at + 2 * self.window {
+            // idle long enough that both buckets are fully stale; reset directly instead of
+            // rotating twice, keeping this O(1) even after a long gap
+            self.buckets[0].clear();
+            self.buckets[1].clear();
+            self.current = 0;
+            self.current_started_at = now;
+            return;
+        }
+
+        self.rotate();
+    }
+
+    #[inline]
+    fn rotate(&mut self) {
+        self.current = 1 - self.current;
+        self.buckets[self.current].clear();
+        self.current_started_at += self.window;
+    }
+}
+
+impl<T> Default for AntiReplay<T>
+where
+    T: Eq + Hash,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_duplicate_token() {
+        let mut register = AntiReplay::with_window(Duration::from_secs(10));
+        let now = Duration::from_secs(4);
+
+        assert_eq!(register.check(*b"token-a", now, now), Outcome::Fresh);
+        assert_eq!(register.check(*b"token-a", now, now), Outcome::Replayed);
+    }
+
+    #[test]
+    fn rejects_token_outside_the_acceptance_window() {
+        let mut register = AntiReplay::with_window(Duration::from_secs(10));
+
+        // the ticket claims to be 11s old, which is outside the 10s window
+        let timestamp = Duration::from_secs(0);
+        let now = Duration::from_secs(11);
+
+        assert_eq!(register.check(*b"token-a", timestamp, now), Outcome::Replayed);
+    }
+
+    #[test]
+    fn remembers_a_token_for_at_least_the_full_window() {
+        // regression test: a naive implementation that rotates buckets every `window / 2`
+        // forgets a token as little as `window / 2` after it is seen, which is an anti-replay
+        // bypass within the advertised acceptance window
+        let mut register = AntiReplay::with_window(Duration::from_secs(10));
+
+        let seen_at = Duration::from_millis(4_900);
+        assert_eq!(register.check(*b"token-a", seen_at, seen_at), Outcome::Fresh);
+
+        // replaying 5.1s later is still well inside the 10s window and must be caught
+        let replay_at = Duration::from_secs(10);
+        assert_eq!(
+            register.check(*b"token-a", seen_at, replay_at),
+            Outcome::Replayed
+        );
+    }
+
+    #[test]
+    fn rejects_a_future_dated_token_beyond_the_skew_tolerance() {
+        let mut register = AntiReplay::with_window(Duration::from_secs(10));
+        let now = Duration::from_secs(4);
+
+        // claiming to be 3s ahead of `now` is well outside the default 2s skew tolerance
+        let timestamp = now + Duration::from_secs(3);
+        assert_eq!(register.check(*b"token-a", timestamp, now), Outcome::Replayed);
+    }
+
+    #[test]
+    fn a_future_dated_token_cannot_outlive_its_own_retention() {
+        // regression test: the old symmetric `|now - timestamp| > window` check let a token
+        // claim a `timestamp` up to a full window ahead of the `now` it was actually first seen
+        // at, but bucket retention is anchored to that `now`, not to `timestamp`. With a
+        // window-sized future tolerance, that forged timestamp is accepted and inserted; a
+        // replay arriving two windows after the real first sighting then still passes the
+        // staleness check (since it looks fresh against the forged `timestamp`) after the
+        // register has already forgotten the token -- demonstrating why `max_future_skew` must
+        // stay much smaller than the acceptance window, as [`DEFAULT_MAX_FUTURE_SKEW`] does.
+        let window = Duration::from_secs(10);
+        let mut register = AntiReplay::with_window_and_max_future_skew(window, window);
+
+        let seen_at = Duration::from_secs(0);
+        let forged_timestamp = seen_at + window;
+        assert_eq!(
+            register.check(*b"token-a", forged_timestamp, seen_at),
+            Outcome::Fresh
+        );
+
+        let replay_at = seen_at + 2 * window;
+        assert_eq!(
+            register.check(*b"token-a", forged_timestamp, replay_at),
+            Outcome::Fresh,
+            "a future tolerance as wide as the acceptance window reopens the bypass \
+             DEFAULT_MAX_FUTURE_SKEW is meant to close"
+        );
+
+        // the default, much tighter skew rejects the forged timestamp outright, so it is never
+        // inserted and the bypass above cannot occur
+        let mut register = AntiReplay::with_window(window);
+        assert_eq!(
+            register.check(*b"token-a", forged_timestamp, seen_at),
+            Outcome::Replayed
+        );
+    }
+
+    #[test]
+    fn reclaims_bucket_memory_once_a_token_is_older_than_two_windows() {
+        let mut register = AntiReplay::with_window(Duration::from_secs(10));
+
+        let seen_at = Duration::from_secs(0);
+        register.check(*b"token-a", seen_at, seen_at);
+
+        // once both buckets have rotated out, the oldest state is reclaimed rather than kept
+        // around forever
+        register.advance(Duration::from_secs(20));
+        assert!(register.buckets.iter().all(HashSet::is_empty));
+    }
+}